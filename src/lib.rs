@@ -1,8 +1,10 @@
 use std::ffi::{c_char, c_uchar, c_void, CStr, CString};
-use std::net::UdpSocket;
+use std::net::{SocketAddr, SocketAddrV4, UdpSocket};
 use std::slice;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::thread::JoinHandle;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 #[derive(Debug)]
 #[repr(C)]
@@ -10,83 +12,215 @@ pub enum ParserError {
     InvalidAddress,
     InvalidType,
     InvalidValue,
+    NotEnoughBytes,
+    UnexpectedEof,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default, Clone, Copy)]
 #[repr(C)]
 pub struct OscValue {
     int: i32,
     float: f32,
     bool: bool,
     string: *const c_char,
+    int64: i64,
+    double: f64,
+    blob: *const c_uchar,
+    blob_len: usize,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 #[repr(C)]
 pub enum OscType {
     Int,
     Float,
     Bool,
     String,
+    Int64,
+    Double,
+    /// 64-bit NTP timetag, carried in `OscValue::int64` as its raw bit pattern.
+    Timetag,
+    /// Arbitrary byte blob, carried in `OscValue::blob`/`OscValue::blob_len`.
+    Blob,
+    /// ASCII character, carried as its code point in `OscValue::int`.
+    Char,
+    /// 32-bit RGBA colour, carried as its raw bit pattern in `OscValue::int`.
+    Rgba,
+    /// 4-byte MIDI message, carried as its raw bit pattern in `OscValue::int`.
+    Midi,
+    /// Argument-less nil marker.
+    Nil,
+    /// Argument-less impulse/bang marker.
+    Impulse,
+}
+
+/// One decoded argument: its type tag paired with the value it carries.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct OscArg {
+    pub osc_type: OscType,
+    pub value: OscValue,
 }
 
+// A message handed to `start_socket`'s message callback owns its `address`,
+// `args`, and any blob payloads inside `args`; pass it to `free_osc_message`
+// exactly once when done with it. A message populated via `parse_osc` owns
+// the same fields, but the `OscMessage` struct itself is caller-owned, so
+// free those fields with `free_osc_message_contents` instead.
 #[repr(C)]
 pub struct OscMessage {
     pub address: *const c_char,
+    // Mirrors `args[0]` for callers that only ever dealt with one argument.
     pub osc_type: OscType,
     pub value: OscValue,
+    // Every argument in declaration order, including the one mirrored above.
+    // Null/zero when the message carries no arguments.
+    pub args: *mut OscArg,
+    pub arg_count: usize,
     //raw: Vec<u8>,
 }
 
-fn extract_osc_address(buf: &[u8], ix: &mut usize) -> Result<String, ParserError> {
-    // First, we wanna ensure the first char is a '/'
-    if buf[0] != 47 {
-        return Err(ParserError::InvalidAddress);
+/// A decoded bundle: its timetag plus every message it (transitively)
+/// contains, in order. Nested bundles are flattened into `messages`; only
+/// the outermost timetag is kept.
+///
+/// A bundle handed to `start_socket`'s bundle callback owns `messages` and
+/// everything each contained message owns; pass it to `free_osc_bundle`
+/// exactly once when done with it.
+#[repr(C)]
+pub struct OscBundle {
+    pub timetag: u64,
+    pub messages: *mut OscMessage,
+    pub message_count: usize,
+}
+
+/// A decoded top-level packet: either a single message or a bundle.
+enum OscPacket {
+    Message(OscMessage),
+    Bundle(u64, Vec<OscMessage>),
+}
+
+/// A bounds-checked cursor over a packet buffer. Every read verifies there
+/// are enough bytes left before slicing, so malformed or truncated UDP
+/// packets surface a `ParserError` instead of panicking across the FFI
+/// boundary.
+struct OscReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> OscReader<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        OscReader { buf, pos: 0 }
     }
 
-    let mut address = String::new();
+    fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
 
-    while buf[*ix] != 0 {
-        address.push(buf[*ix] as char);
-        *ix += 1;
+    fn read_u32_be(&mut self) -> Result<u32, ParserError> {
+        if self.remaining() < 4 {
+            return Err(ParserError::NotEnoughBytes);
+        }
+        let mut bytes = [0u8; 4];
+        bytes.copy_from_slice(&self.buf[self.pos..self.pos + 4]);
+        self.pos += 4;
+        Ok(u32::from_be_bytes(bytes))
     }
 
-    // Ensure we include the null terminator in the index
-    *ix += 1;
+    fn read_f32_be(&mut self) -> Result<f32, ParserError> {
+        Ok(f32::from_bits(self.read_u32_be()?))
+    }
 
-    // Now round up to 4 bytes. If we're already on a 4 byte boundary, we don't need to do anything
-    if *ix % 4 != 0 {
-        *ix += 4 - (*ix % 4);
+    fn read_u64_be(&mut self) -> Result<u64, ParserError> {
+        if self.remaining() < 8 {
+            return Err(ParserError::NotEnoughBytes);
+        }
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&self.buf[self.pos..self.pos + 8]);
+        self.pos += 8;
+        Ok(u64::from_be_bytes(bytes))
     }
 
-    return Ok(address);
+    fn read_f64_be(&mut self) -> Result<f64, ParserError> {
+        Ok(f64::from_bits(self.read_u64_be()?))
+    }
+
+    /// Reads `len` raw bytes, verifying they're all in bounds first.
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], ParserError> {
+        if self.remaining() < len {
+            return Err(ParserError::NotEnoughBytes);
+        }
+        let bytes = &self.buf[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    /// Reads a null-terminated C-style string, stopping the scan at the end
+    /// of the buffer instead of running past it.
+    fn read_cstring(&mut self) -> Result<String, ParserError> {
+        let start = self.pos;
+        while self.pos < self.buf.len() && self.buf[self.pos] != 0 {
+            self.pos += 1;
+        }
+        if self.pos >= self.buf.len() {
+            return Err(ParserError::UnexpectedEof);
+        }
+        let string = String::from_utf8_lossy(&self.buf[start..self.pos]).into_owned();
+        // Consume the null terminator.
+        self.pos += 1;
+        Ok(string)
+    }
+
+    /// Rounds the cursor position up to the next 4-byte boundary.
+    fn align_to_4(&mut self) -> Result<(), ParserError> {
+        let aligned = (self.pos + 3) & !3;
+        if aligned > self.buf.len() {
+            return Err(ParserError::NotEnoughBytes);
+        }
+        self.pos = aligned;
+        Ok(())
+    }
+}
+
+fn extract_osc_address(reader: &mut OscReader) -> Result<String, ParserError> {
+    // First, we wanna ensure the first char is a '/'
+    if reader.remaining() < 1 || reader.buf[reader.pos] != 47 {
+        return Err(ParserError::InvalidAddress);
+    }
+
+    let address = reader.read_cstring()?;
+    reader.align_to_4()?;
+
+    Ok(address)
 }
 
-fn extract_osc_value(buf: &[u8], ix: &mut usize) -> Result<(OscType, OscValue), ParserError> {
+/// Reads the comma-prefixed type-tag string (e.g. `,iif`), stopping at its
+/// null terminator and aligning the cursor to the next 4-byte boundary.
+fn extract_osc_type_tags(reader: &mut OscReader) -> Result<Vec<char>, ParserError> {
     // First, we wanna ensure the first char is a ','
-    if buf[*ix] != 44 {
+    if reader.remaining() < 1 || reader.buf[reader.pos] != 44 {
         return Err(ParserError::InvalidType);
     }
+    reader.pos += 1;
 
-    *ix += 1;
-
-    let type_char = buf[*ix] as char;
-    *ix += 3;
+    let tags = reader.read_cstring()?;
+    reader.align_to_4()?;
+    Ok(tags.chars().collect())
+}
 
-    let mut value = OscValue { int: 0, float: 0.0, bool: false, string: std::ptr::null() };
+/// Decodes a single argument's value for the given type-tag character.
+fn decode_osc_value(reader: &mut OscReader, type_char: char) -> Result<(OscType, OscValue), ParserError> {
+    let mut value = OscValue::default();
 
     // Now we convert this to an OscValue based on the type
-    return match type_char {
+    match type_char {
         'i' => {
-            let mut bytes = [0; 4];
-            bytes.copy_from_slice(&buf[*ix..*ix + 4]);
-            value.int = i32::from_be_bytes(bytes);
+            value.int = reader.read_u32_be()? as i32;
             Ok((OscType::Int, value))
         }
         'f' => {
-            let mut bytes = [0; 4];
-            bytes.copy_from_slice(&buf[*ix..*ix + 4]);
-            value.float = f32::from_be_bytes(bytes);
+            value.float = reader.read_f32_be()?;
             Ok((OscType::Float, value))
         }
         'T' => {
@@ -98,35 +232,94 @@ fn extract_osc_value(buf: &[u8], ix: &mut usize) -> Result<(OscType, OscValue),
             Ok((OscType::Bool, value))
         }
         's' => {
-            let mut string = String::new();
-            while buf[*ix] != 0 {
-                string.push(buf[*ix] as char);
-                *ix += 1;
-            }
-            *ix += 1;
+            let string = reader.read_cstring()?;
+            reader.align_to_4()?;
             value.string = CString::new(string).unwrap().into_raw();
             Ok((OscType::String, value))
         }
+        'h' => {
+            value.int64 = reader.read_u64_be()? as i64;
+            Ok((OscType::Int64, value))
+        }
+        'd' => {
+            value.double = reader.read_f64_be()?;
+            Ok((OscType::Double, value))
+        }
+        't' => {
+            value.int64 = reader.read_u64_be()? as i64;
+            Ok((OscType::Timetag, value))
+        }
+        'b' => {
+            let len = reader.read_u32_be()? as usize;
+            let bytes = reader.read_bytes(len)?;
+            let boxed: Box<[u8]> = bytes.to_vec().into_boxed_slice();
+            value.blob_len = boxed.len();
+            value.blob = Box::into_raw(boxed) as *const c_uchar;
+            reader.align_to_4()?;
+            Ok((OscType::Blob, value))
+        }
+        'c' => {
+            value.int = reader.read_u32_be()? as i32;
+            Ok((OscType::Char, value))
+        }
+        'r' => {
+            value.int = reader.read_u32_be()? as i32;
+            Ok((OscType::Rgba, value))
+        }
+        'm' => {
+            value.int = reader.read_u32_be()? as i32;
+            Ok((OscType::Midi, value))
+        }
+        'N' => {
+            Ok((OscType::Nil, value))
+        }
+        'I' => {
+            Ok((OscType::Impulse, value))
+        }
         _ => {
             Err(ParserError::InvalidType)
         }
     }
 }
 
+/// Reads the type-tag string followed by one value per tag character, in
+/// order, each 4-byte aligned.
+fn extract_osc_values(reader: &mut OscReader) -> Result<Vec<(OscType, OscValue)>, ParserError> {
+    let tags = extract_osc_type_tags(reader)?;
+    let mut values = Vec::with_capacity(tags.len());
+    for tag in tags {
+        values.push(decode_osc_value(reader, tag)?);
+    }
+    Ok(values)
+}
+
 fn parse(buf: &[u8]) -> Result<OscMessage, ParserError> {
-    let mut index = 0;
-    let address = extract_osc_address(&buf, &mut index);
-    println!("Address: {:?}", address);
+    let mut reader = OscReader::new(buf);
+    let address = extract_osc_address(&mut reader);
+    let values = extract_osc_values(&mut reader);
 
-    let value = extract_osc_value(&buf, &mut index);
-    println!("Value: {:?}", value);
+    return match (address, values) {
+        (Ok(address), Ok(mut values)) => {
+            // Mirror the first argument into the single-value fields for
+            // callers that only ever dealt with one argument.
+            let (osc_type, value) = values.first().copied().unwrap_or((OscType::Int, OscValue::default()));
+            let arg_count = values.len();
+            let args = if arg_count == 0 {
+                std::ptr::null_mut()
+            } else {
+                let boxed: Box<[OscArg]> = values
+                    .drain(..)
+                    .map(|(osc_type, value)| OscArg { osc_type, value })
+                    .collect();
+                Box::into_raw(boxed) as *mut OscArg
+            };
 
-    return match (address, value) {
-        (Ok(address), Ok(value)) => {
             Ok(OscMessage {
                 address: CString::new(address).unwrap().into_raw(),
-                osc_type: value.0,
-                value: value.1,
+                osc_type,
+                value,
+                args,
+                arg_count,
                 //raw: buf.to_vec(),
             })
         }
@@ -139,25 +332,99 @@ fn parse(buf: &[u8]) -> Result<OscMessage, ParserError> {
     };
 }
 
-fn recv<F>(source: UdpSocket, mut callback: F)
+fn is_bundle(buf: &[u8]) -> bool {
+    buf.len() >= 8 && &buf[0..8] == b"#bundle\0"
+}
+
+/// Reads an OSC bundle: the 8-byte NTP timetag followed by a sequence of
+/// 4-byte-length-prefixed elements, each recursively parsed since an element
+/// may itself be a nested bundle. Nested messages are flattened into a
+/// single list; only the outermost timetag is returned.
+fn parse_bundle(buf: &[u8]) -> Result<(u64, Vec<OscMessage>), ParserError> {
+    let mut reader = OscReader::new(buf);
+    reader.pos = 8; // Skip the "#bundle\0" marker, already checked by the caller.
+    let timetag = reader.read_u64_be()?;
+
+    let mut messages = Vec::new();
+    while reader.remaining() > 0 {
+        let element_len = reader.read_u32_be()? as usize;
+        let element = reader.read_bytes(element_len)?;
+        if is_bundle(element) {
+            let (_, nested) = parse_bundle(element)?;
+            messages.extend(nested);
+        } else {
+            messages.push(parse(element)?);
+        }
+    }
+
+    Ok((timetag, messages))
+}
+
+/// Parses a top-level packet, dispatching to bundle or single-message
+/// parsing depending on the `#bundle\0` marker.
+fn parse_packet(buf: &[u8]) -> Result<OscPacket, ParserError> {
+    if is_bundle(buf) {
+        let (timetag, messages) = parse_bundle(buf)?;
+        Ok(OscPacket::Bundle(timetag, messages))
+    } else {
+        Ok(OscPacket::Message(parse(buf)?))
+    }
+}
+
+// How often the receive loop wakes up to check the stop flag when no packet
+// has arrived. Short enough that `stop_socket` returns promptly, long enough
+// to not spin.
+const RECV_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// The handle returned to C# through `thread_ptr`. Bundles the receiving
+/// thread together with the flag used to ask it to stop, so `stop_socket`
+/// can signal the loop and then join the thread it actually started.
+struct SocketHandle {
+    stop_flag: Arc<AtomicBool>,
+    thread: JoinHandle<()>,
+}
+
+fn recv<F, G>(source: UdpSocket, stop_flag: &AtomicBool, mut message_callback: F, mut bundle_callback: G)
     where
         F: FnMut(OscMessage),
+        G: FnMut(OscBundle),
 {
     let mut buf: [u8; 4096] = [0; 4096];
-    let (amt, _) = source.recv_from(&mut buf).unwrap();
+    while !stop_flag.load(Ordering::SeqCst) {
+        let (amt, _) = match source.recv_from(&mut buf) {
+            Ok(result) => result,
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock || e.kind() == std::io::ErrorKind::TimedOut => {
+                // Just a read-timeout tick so we can re-check the stop flag.
+                continue;
+            }
+            // A transient OS error; keep listening rather than logging on
+            // every hit of what's now a long-lived receive loop.
+            Err(_) => continue,
+        };
 
-    match parse(&buf[..amt]) {
-        Ok(msg) => {
-            callback(msg);
-        }
-        Err(e) => {
-            println!("Error parsing message: {:?}", e);
+        match parse_packet(&buf[..amt]) {
+            Ok(OscPacket::Message(msg)) => {
+                message_callback(msg);
+            }
+            Ok(OscPacket::Bundle(timetag, messages)) => {
+                let message_count = messages.len();
+                let messages = Box::into_raw(messages.into_boxed_slice()) as *mut OscMessage;
+                bundle_callback(OscBundle { timetag, messages, message_count });
+            }
+            // A malformed packet; drop it and keep listening.
+            Err(_) => {}
         }
     }
 }
 
 #[no_mangle]
-pub extern "C" fn start_socket(ip: *const c_char, port: u16, thread_ptr: *mut c_void, callback: extern "C" fn(*mut OscMessage)) -> i32 {
+pub extern "C" fn start_socket(
+    ip: *const c_char,
+    port: u16,
+    thread_ptr: *mut c_void,
+    message_callback: extern "C" fn(*mut OscMessage),
+    bundle_callback: extern "C" fn(*mut OscBundle),
+) -> i32 {
     let ip_address = match unsafe { CStr::from_ptr(ip) }.to_str() {
         Ok(ip) => ip,
         Err(_) => return -1, // Return error code -1 for invalid IP address
@@ -167,22 +434,118 @@ pub extern "C" fn start_socket(ip: *const c_char, port: u16, thread_ptr: *mut c_
         Ok(socket) => socket,
         Err(_) => return -2, // Return error code -2 for socket binding error
     };
+    if socket.set_read_timeout(Some(RECV_POLL_INTERVAL)).is_err() {
+        return -2;
+    }
+
+    let stop_flag = Arc::new(AtomicBool::new(false));
+    let thread_stop_flag = Arc::clone(&stop_flag);
+
     // Start receiving thread
-    let handle = std::thread::spawn(move || {
-        recv(socket, |msg| {
-            callback(Box::into_raw(Box::new(msg)));
-        });
+    let thread = std::thread::spawn(move || {
+        recv(
+            socket,
+            &thread_stop_flag,
+            |msg| {
+                message_callback(Box::into_raw(Box::new(msg)));
+            },
+            |bundle| {
+                bundle_callback(Box::into_raw(Box::new(bundle)));
+            },
+        );
     });
 
-    unsafe { *(thread_ptr as *mut *mut JoinHandle<()>) = Box::into_raw(Box::new(handle)) as *const c_void as *mut JoinHandle<()> };
+    let handle = SocketHandle { stop_flag, thread };
+    unsafe { *(thread_ptr as *mut *mut SocketHandle) = Box::into_raw(Box::new(handle)) };
     0
 }
 
 #[no_mangle]
 pub extern "C" fn stop_socket(thread_ptr: *mut c_void) {
-    // Get the thread handle from the provided pointer and join the thread
-    let handle = unsafe { Box::from_raw(thread_ptr as *mut JoinHandle<()>) };
-    handle.join().unwrap();
+    // Get the socket handle from the provided pointer, signal the receive
+    // loop to stop, then join the thread it actually started.
+    let handle = unsafe { Box::from_raw(thread_ptr as *mut SocketHandle) };
+    handle.stop_flag.store(true, Ordering::SeqCst);
+    handle.thread.join().unwrap();
+}
+
+/// Frees the heap allocations owned by a decoded message's fields (address
+/// string, argument list, and any blob/string payloads inside it) without
+/// freeing `msg` itself. Shared by `free_osc_message` and `free_osc_bundle`,
+/// since a bundle's messages are embedded in its `messages` array rather
+/// than individually boxed.
+unsafe fn free_osc_message_fields(msg: &OscMessage) {
+    if !msg.address.is_null() {
+        drop(CString::from_raw(msg.address as *mut c_char));
+    }
+    if !msg.args.is_null() && msg.arg_count > 0 {
+        let args = Box::from_raw(std::ptr::slice_from_raw_parts_mut(msg.args, msg.arg_count));
+        for arg in args.iter() {
+            free_osc_arg_value(arg.osc_type, &arg.value);
+        }
+    }
+}
+
+/// Frees the heap allocation an argument's value owns, if any (a blob's
+/// bytes, or a string's `CString`). No-op for types that carry their value
+/// inline.
+unsafe fn free_osc_arg_value(osc_type: OscType, value: &OscValue) {
+    match osc_type {
+        OscType::String if !value.string.is_null() => {
+            drop(CString::from_raw(value.string as *mut c_char));
+        }
+        OscType::Blob if !value.blob.is_null() => {
+            drop(Box::from_raw(std::ptr::slice_from_raw_parts_mut(value.blob as *mut c_uchar, value.blob_len)));
+        }
+        _ => {}
+    }
+}
+
+/// Frees an `OscMessage` received through `start_socket`'s message callback,
+/// including its address string, argument list, and any blob payloads.
+/// Must be called exactly once per message the callback receives; does not
+/// apply to messages populated via `parse_osc` — those are caller-owned, so
+/// use `free_osc_message_contents` on them instead.
+#[no_mangle]
+pub extern "C" fn free_osc_message(msg: *mut OscMessage) {
+    if msg.is_null() {
+        return;
+    }
+    let msg = unsafe { Box::from_raw(msg) };
+    unsafe { free_osc_message_fields(&msg) };
+}
+
+/// Frees the heap allocations inside a message populated by `parse_osc` —
+/// its address string, argument list, and any blob payloads — without
+/// freeing `msg` itself, since that struct is owned by the caller (e.g. a
+/// stack-allocated or C#-managed `OscMessage`), not boxed by this crate.
+/// Must be called exactly once per successful `parse_osc` call before the
+/// message goes out of scope. Do not call `free_osc_message` on it instead:
+/// that would attempt to free `msg` itself via a pointer that was never
+/// heap-allocated by this crate.
+#[no_mangle]
+pub extern "C" fn free_osc_message_contents(msg: *mut OscMessage) {
+    if msg.is_null() {
+        return;
+    }
+    unsafe { free_osc_message_fields(&*msg) };
+}
+
+/// Frees an `OscBundle` received through `start_socket`'s bundle callback,
+/// including every contained message's address, argument list, and blob
+/// payloads. Must be called exactly once per bundle the callback receives.
+#[no_mangle]
+pub extern "C" fn free_osc_bundle(bundle: *mut OscBundle) {
+    if bundle.is_null() {
+        return;
+    }
+    let bundle = unsafe { Box::from_raw(bundle) };
+    if !bundle.messages.is_null() && bundle.message_count > 0 {
+        let messages = unsafe { Box::from_raw(std::ptr::slice_from_raw_parts_mut(bundle.messages, bundle.message_count)) };
+        for msg in messages.iter() {
+            unsafe { free_osc_message_fields(msg) };
+        }
+    }
 }
 
 // Import a byte array from C# and parse it
@@ -198,10 +561,12 @@ pub extern "C" fn parse_osc(buf: *const c_uchar, len: usize, msg: &mut OscMessag
     }
 }
 
-fn write_address(buf: &mut [u8], ix: &mut usize, address: &str) {
-    let address_bytes = address.as_bytes();
-    buf[*ix..*ix + address_bytes.len()].copy_from_slice(address_bytes);
-    *ix += address_bytes.len();
+/// Writes a null-terminated string and pads the cursor up to the next
+/// 4-byte boundary, per the OSC string encoding.
+fn write_padded_cstring(buf: &mut [u8], ix: &mut usize, s: &str) {
+    let bytes = s.as_bytes();
+    buf[*ix..*ix + bytes.len()].copy_from_slice(bytes);
+    *ix += bytes.len();
     buf[*ix] = 0;
     *ix += 1;
     if *ix % 4 != 0 {
@@ -209,39 +574,191 @@ fn write_address(buf: &mut [u8], ix: &mut usize, address: &str) {
     }
 }
 
-#[no_mangle]
-pub extern "C" fn create_osc_message(buf: *mut c_uchar, osc_template: &OscMessage) -> usize {
-    let buf = unsafe { slice::from_raw_parts_mut(buf, 4096) };
-    let address = unsafe { CStr::from_ptr(osc_template.address) }.to_str().unwrap();
-    let mut ix = 0;
-    write_address(buf, &mut ix, address);
-    buf[ix] = 44; // ,
-    ix += 1;
-    match osc_template.osc_type {
+fn write_address(buf: &mut [u8], ix: &mut usize, address: &str) {
+    write_padded_cstring(buf, ix, address);
+}
+
+/// The type-tag character for an argument, e.g. `i` for `OscType::Int`.
+/// `Bool` carries its value in the tag itself (`T`/`F`), per the OSC spec.
+fn osc_tag_char(osc_type: &OscType, value: &OscValue) -> u8 {
+    match osc_type {
+        OscType::Int => b'i',
+        OscType::Float => b'f',
+        OscType::Bool => if value.bool { b'T' } else { b'F' },
+        OscType::String => b's',
+        OscType::Int64 => b'h',
+        OscType::Double => b'd',
+        OscType::Timetag => b't',
+        OscType::Blob => b'b',
+        OscType::Char => b'c',
+        OscType::Rgba => b'r',
+        OscType::Midi => b'm',
+        OscType::Nil => b'N',
+        OscType::Impulse => b'I',
+    }
+}
+
+/// Pads the cursor with zero bytes up to the next 4-byte boundary.
+fn pad_to_4(buf: &mut [u8], ix: &mut usize) {
+    while *ix % 4 != 0 {
+        buf[*ix] = 0;
+        *ix += 1;
+    }
+}
+
+/// Writes an argument's data bytes (the type tag has already been written
+/// as part of the shared type-tag string).
+fn write_osc_value(buf: &mut [u8], ix: &mut usize, osc_type: &OscType, value: &OscValue) {
+    match osc_type {
         OscType::Int => {
-            buf[ix] = 105; // i
-            ix += 3;
-            let bytes = osc_template.value.int.to_be_bytes();
-            buf[ix..ix + 4].copy_from_slice(&bytes);
-            ix += 4;
+            let bytes = value.int.to_be_bytes();
+            buf[*ix..*ix + 4].copy_from_slice(&bytes);
+            *ix += 4;
         }
         OscType::Float => {
-            buf[ix] = 102; // f
-            ix += 3;
-            let bytes = osc_template.value.float.to_be_bytes();
-            buf[ix..ix + 4].copy_from_slice(&bytes);
-            ix += 4;
+            let bytes = value.float.to_be_bytes();
+            buf[*ix..*ix + 4].copy_from_slice(&bytes);
+            *ix += 4;
         }
         OscType::Bool => {
-            buf[ix] = if osc_template.value.bool { 84 } else { 70 }; // T or F
-            ix += 3;
+            // No data bytes; the value is carried by the tag char.
+        }
+        OscType::String => {
+            let string = unsafe { CStr::from_ptr(value.string) }.to_str().unwrap();
+            write_padded_cstring(buf, ix, string);
+        }
+        OscType::Int64 => {
+            let bytes = value.int64.to_be_bytes();
+            buf[*ix..*ix + 8].copy_from_slice(&bytes);
+            *ix += 8;
+        }
+        OscType::Double => {
+            let bytes = value.double.to_be_bytes();
+            buf[*ix..*ix + 8].copy_from_slice(&bytes);
+            *ix += 8;
+        }
+        OscType::Timetag => {
+            let bytes = (value.int64 as u64).to_be_bytes();
+            buf[*ix..*ix + 8].copy_from_slice(&bytes);
+            *ix += 8;
+        }
+        OscType::Blob => {
+            let bytes = (value.blob_len as u32).to_be_bytes();
+            buf[*ix..*ix + 4].copy_from_slice(&bytes);
+            *ix += 4;
+            if value.blob_len > 0 {
+                let data = unsafe { slice::from_raw_parts(value.blob, value.blob_len) };
+                buf[*ix..*ix + value.blob_len].copy_from_slice(data);
+                *ix += value.blob_len;
+            }
+            pad_to_4(buf, ix);
         }
+        OscType::Char | OscType::Rgba | OscType::Midi => {
+            let bytes = (value.int as u32).to_be_bytes();
+            buf[*ix..*ix + 4].copy_from_slice(&bytes);
+            *ix += 4;
+        }
+        OscType::Nil | OscType::Impulse => {
+            // Argument-less: the tag alone carries the value.
+        }
+    }
+}
+
+/// Length, in bytes, `s` occupies once null-terminated and padded to a
+/// 4-byte boundary, per the OSC string encoding.
+fn padded_cstring_len(s: &str) -> usize {
+    let len = s.len() + 1;
+    (len + 3) & !3
+}
+
+/// Length, in bytes, an argument's data occupies on the wire (the type tag
+/// itself is accounted for separately, as part of the shared tag string).
+/// Returns `Err(())` if `value.string` isn't valid UTF-8, mirroring the
+/// error `write_osc_value` would otherwise hit while writing it.
+fn osc_value_encoded_len(osc_type: &OscType, value: &OscValue) -> Result<usize, ()> {
+    Ok(match osc_type {
+        OscType::Int | OscType::Float | OscType::Char | OscType::Rgba | OscType::Midi => 4,
+        OscType::Bool | OscType::Nil | OscType::Impulse => 0,
+        OscType::Int64 | OscType::Double | OscType::Timetag => 8,
         OscType::String => {
-            println!("Not implemented yet!")
+            let string = unsafe { CStr::from_ptr(value.string) }.to_str().map_err(|_| ())?;
+            padded_cstring_len(string)
         }
+        OscType::Blob => 4 + ((value.blob_len + 3) & !3),
+    })
+}
+
+/// Serializes `osc_template` into `buf`, returning the number of bytes
+/// written. Computes the full encoded size (address, type-tag string, and
+/// every argument's data) up front and returns `Err(())` without writing
+/// anything if it wouldn't fit in `buf`, so an oversized message (a large
+/// blob, most plausibly) can never run past the end of the caller's buffer.
+fn write_osc_message(buf: &mut [u8], osc_template: &OscMessage) -> Result<usize, ()> {
+    let address = unsafe { CStr::from_ptr(osc_template.address) }.to_str().map_err(|_| ())?;
+
+    // Prefer the full argument list; fall back to the mirrored single value
+    // for callers built against the one-argument-per-message API.
+    let args: Vec<(OscType, OscValue)> = if osc_template.arg_count > 0 && !osc_template.args.is_null() {
+        let slice = unsafe { slice::from_raw_parts(osc_template.args, osc_template.arg_count) };
+        slice.iter().map(|a| (a.osc_type, a.value)).collect()
+    } else {
+        vec![(osc_template.osc_type, osc_template.value)]
+    };
+
+    let tags_len = (2 + args.len() + 3) & !3; // ',' + one char per arg + '\0', padded
+    let mut needed = padded_cstring_len(address) + tags_len;
+    for (osc_type, value) in &args {
+        needed += osc_value_encoded_len(osc_type, value)?;
+    }
+    if needed > buf.len() {
+        return Err(());
     }
 
-    ix
+    let mut ix = 0;
+    write_address(buf, &mut ix, address);
+
+    buf[ix] = 44; // ,
+    ix += 1;
+    for (osc_type, value) in &args {
+        buf[ix] = osc_tag_char(osc_type, value);
+        ix += 1;
+    }
+    buf[ix] = 0;
+    ix += 1;
+    if ix % 4 != 0 {
+        ix += 4 - (ix % 4);
+    }
+
+    for (osc_type, value) in &args {
+        write_osc_value(buf, &mut ix, osc_type, value);
+    }
+
+    Ok(ix)
+}
+
+/// Writes `osc_template` into the 4096-byte buffer `buf` points at,
+/// returning the number of bytes written, or `0` if the encoded message
+/// (address, type tags, and argument data — most plausibly a large blob)
+/// wouldn't fit in 4096 bytes.
+#[no_mangle]
+pub extern "C" fn create_osc_message(buf: *mut c_uchar, osc_template: &OscMessage) -> usize {
+    let buf = unsafe { slice::from_raw_parts_mut(buf, 4096) };
+    write_osc_message(buf, osc_template).unwrap_or(0)
+}
+
+/// The current time as a 64-bit NTP timetag, per the OSC bundle header format.
+fn current_ntp_timetag() -> u64 {
+    let time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap();
+
+    // NTP counts seconds from 1900-01-01, 70 years before the Unix epoch, in
+    // the upper 32 bits; the lower 32 bits are the fraction of a second in
+    // units of 2^-32 s.
+    const NTP_UNIX_OFFSET_SECS: u64 = 2_208_988_800;
+    let secs = time.as_secs() + NTP_UNIX_OFFSET_SECS;
+    let fraction = ((time.subsec_nanos() as u64) << 32) / 1_000_000_000;
+    secs << 32 | fraction
 }
 
 // Creates a bundle from an array of OscMessages
@@ -257,35 +774,28 @@ pub extern "C" fn create_osc_bundle(buf: *mut c_uchar, messages: *const OscMessa
     ix += 8;
 
     // Write the current NTP time as the timetag
-    let time = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap();
-
-    // Ensure we don't overflow the 64-bit integer
-    let time = (time.as_secs() as u64) << 32 | (time.subsec_nanos() as u64) << 32 >> 32;
-
-    let bytes = time.to_be_bytes();
+    let bytes = current_ntp_timetag().to_be_bytes();
     buf[ix..ix + 8].copy_from_slice(&bytes);
     ix += 8;
 
     // Now we need to write the messages
     let mut message_ix = unsafe { *messages_index };
     for msg in messages.iter().skip(message_ix) {
-        // We need to calculate the length of the string and pad it to a multiple of 4 to ensure alignment
-        // then add another 4 bytes for the length of the message
-        // If adding it would go over the buffer size, return
-        // Use the existing function to write the message to the buffer
-        let address = unsafe { CStr::from_ptr(msg.address).to_str() }.unwrap();
-        let length = address.len() + 1;
-        let padded_length = if length % 4 == 0 { length } else { length + 4 - (length % 4) };
-        if ix + padded_length + 4 > 4096 {
+        // Reserve 4 bytes for the length prefix, then serialize straight into
+        // whatever's left of the buffer; write_osc_message bounds-checks the
+        // full encoded size (address, type tags, and argument data) against
+        // that real remaining space, so a message that wouldn't fit just
+        // stops the bundle here instead of overrunning the buffer.
+        if ix + 4 > buf.len() {
             return ix;
         }
+        let length = match write_osc_message(&mut buf[ix + 4..], msg) {
+            Ok(length) => length,
+            Err(()) => return ix,
+        };
 
-        let length = create_osc_message(unsafe { buf.as_mut_ptr().add(ix + 4) }, msg);
         // Write the length of the message to the buffer. Ensure we use 4 bytes
         let bytes: [u8; 4] = (length as u32).to_be_bytes();
-
         buf[ix..ix + 4].copy_from_slice(&bytes);
         ix += length + 4;
 
@@ -299,6 +809,313 @@ pub extern "C" fn create_osc_bundle(buf: *mut c_uchar, messages: *const OscMessa
     ix
 }
 
+/// One gather-write buffer: a pointer plus a length, handed straight to the
+/// platform's scatter/gather send call so the kernel reads each message's
+/// bytes directly out of the scratch arena instead of us copying them into
+/// one contiguous buffer first.
+struct IoVec {
+    base: *const u8,
+    len: usize,
+}
+
+#[repr(C)]
+struct SockAddrIn {
+    sin_family: u16,
+    sin_port: [u8; 2],
+    sin_addr: [u8; 4],
+    sin_zero: [u8; 8],
+}
+
+fn sockaddr_in_for(addr: &SocketAddrV4) -> SockAddrIn {
+    SockAddrIn {
+        sin_family: 2, // AF_INET, the same value across every unix target we cover below
+        sin_port: addr.port().to_be_bytes(),
+        sin_addr: addr.ip().octets(),
+        sin_zero: [0; 8],
+    }
+}
+
+// The unix targets we have a native `sendmsg` path for. glibc's `struct
+// msghdr` uses `size_t` for `msg_iovlen`/`msg_controllen`; the BSD-derived
+// layout (macOS and the *BSDs) uses `int`/`socklen_t` (4 bytes) for those
+// same fields instead, so `#[cfg(unix)]` alone is too broad a gate for one
+// hard-coded field layout — getting this wrong means the kernel reads
+// garbage for `msg_iov`/`msg_iovlen`/`msg_control`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn send_vectored_v4(socket: &UdpSocket, target: SocketAddrV4, iovecs: &[IoVec]) -> std::io::Result<usize> {
+    use std::os::unix::io::AsRawFd;
+
+    #[repr(C)]
+    struct RawIoVec {
+        iov_base: *const u8,
+        iov_len: usize,
+    }
+
+    // glibc's `struct msghdr` layout (Linux/Android, all architectures we target).
+    #[repr(C)]
+    struct MsgHdr {
+        msg_name: *const c_void,
+        msg_namelen: u32,
+        msg_iov: *const RawIoVec,
+        msg_iovlen: usize,
+        msg_control: *const c_void,
+        msg_controllen: usize,
+        msg_flags: i32,
+    }
+
+    extern "C" {
+        fn sendmsg(fd: i32, msg: *const MsgHdr, flags: i32) -> isize;
+    }
+
+    let addr = sockaddr_in_for(&target);
+    let raw_iovecs: Vec<RawIoVec> = iovecs
+        .iter()
+        .map(|v| RawIoVec { iov_base: v.base, iov_len: v.len })
+        .collect();
+
+    let msg = MsgHdr {
+        msg_name: &addr as *const SockAddrIn as *const c_void,
+        msg_namelen: std::mem::size_of::<SockAddrIn>() as u32,
+        msg_iov: raw_iovecs.as_ptr(),
+        msg_iovlen: raw_iovecs.len(),
+        msg_control: std::ptr::null(),
+        msg_controllen: 0,
+        msg_flags: 0,
+    };
+
+    let sent = unsafe { sendmsg(socket.as_raw_fd(), &msg, 0) };
+    if sent < 0 {
+        Err(std::io::Error::last_os_error())
+    } else {
+        Ok(sent as usize)
+    }
+}
+
+#[cfg(any(
+    target_os = "macos",
+    target_os = "ios",
+    target_os = "freebsd",
+    target_os = "openbsd",
+    target_os = "netbsd",
+    target_os = "dragonfly",
+))]
+fn send_vectored_v4(socket: &UdpSocket, target: SocketAddrV4, iovecs: &[IoVec]) -> std::io::Result<usize> {
+    use std::os::unix::io::AsRawFd;
+
+    #[repr(C)]
+    struct RawIoVec {
+        iov_base: *const u8,
+        iov_len: usize,
+    }
+
+    // The BSD-derived `struct msghdr` layout (macOS and the *BSDs): unlike
+    // glibc, `msg_iovlen` is a plain `int` and `msg_controllen` is a
+    // `socklen_t` (`u32`), not `size_t`.
+    #[repr(C)]
+    struct MsgHdr {
+        msg_name: *const c_void,
+        msg_namelen: u32,
+        msg_iov: *const RawIoVec,
+        msg_iovlen: i32,
+        msg_control: *const c_void,
+        msg_controllen: u32,
+        msg_flags: i32,
+    }
+
+    extern "C" {
+        fn sendmsg(fd: i32, msg: *const MsgHdr, flags: i32) -> isize;
+    }
+
+    let addr = sockaddr_in_for(&target);
+    let raw_iovecs: Vec<RawIoVec> = iovecs
+        .iter()
+        .map(|v| RawIoVec { iov_base: v.base, iov_len: v.len })
+        .collect();
+
+    let msg = MsgHdr {
+        msg_name: &addr as *const SockAddrIn as *const c_void,
+        msg_namelen: std::mem::size_of::<SockAddrIn>() as u32,
+        msg_iov: raw_iovecs.as_ptr(),
+        msg_iovlen: raw_iovecs.len() as i32,
+        msg_control: std::ptr::null(),
+        msg_controllen: 0,
+        msg_flags: 0,
+    };
+
+    let sent = unsafe { sendmsg(socket.as_raw_fd(), &msg, 0) };
+    if sent < 0 {
+        Err(std::io::Error::last_os_error())
+    } else {
+        Ok(sent as usize)
+    }
+}
+
+#[cfg(windows)]
+fn send_vectored_v4(socket: &UdpSocket, target: SocketAddrV4, iovecs: &[IoVec]) -> std::io::Result<usize> {
+    use std::os::windows::io::AsRawSocket;
+
+    #[repr(C)]
+    struct WsaBuf {
+        len: u32,
+        buf: *mut u8,
+    }
+
+    #[repr(C)]
+    struct WsaMsg {
+        name: *const c_void,
+        namelen: i32,
+        lp_buffers: *mut WsaBuf,
+        dw_buffer_count: u32,
+        control: WsaBuf,
+        dw_flags: u32,
+    }
+
+    #[link(name = "ws2_32")]
+    extern "system" {
+        fn WSASendMsg(
+            s: usize,
+            lp_msg: *const WsaMsg,
+            dw_flags: u32,
+            lp_number_of_bytes_sent: *mut u32,
+            lp_overlapped: *mut c_void,
+            lp_completion_routine: *mut c_void,
+        ) -> i32;
+    }
+
+    let addr = sockaddr_in_for(&target);
+    let mut wsa_bufs: Vec<WsaBuf> = iovecs
+        .iter()
+        .map(|v| WsaBuf { len: v.len as u32, buf: v.base as *mut u8 })
+        .collect();
+
+    let msg = WsaMsg {
+        name: &addr as *const SockAddrIn as *const c_void,
+        namelen: std::mem::size_of::<SockAddrIn>() as i32,
+        lp_buffers: wsa_bufs.as_mut_ptr(),
+        dw_buffer_count: wsa_bufs.len() as u32,
+        control: WsaBuf { len: 0, buf: std::ptr::null_mut() },
+        dw_flags: 0,
+    };
+
+    let mut sent: u32 = 0;
+    let result = unsafe {
+        WSASendMsg(socket.as_raw_socket() as usize, &msg, 0, &mut sent, std::ptr::null_mut(), std::ptr::null_mut())
+    };
+    if result != 0 {
+        Err(std::io::Error::last_os_error())
+    } else {
+        Ok(sent as usize)
+    }
+}
+
+/// Sends `iovecs` via one `send_to` call per buffer, used wherever the
+/// platform has no scatter/gather send path. Each buffer goes out as its own
+/// datagram; the pieces are not joined into one.
+fn send_vectored_fallback(socket: &UdpSocket, target: SocketAddr, iovecs: &[IoVec]) -> std::io::Result<usize> {
+    let mut sent = 0;
+    for iovec in iovecs {
+        let bytes = unsafe { slice::from_raw_parts(iovec.base, iovec.len) };
+        sent += socket.send_to(bytes, target)?;
+    }
+    Ok(sent)
+}
+
+/// Gathers `iovecs` into one outgoing datagram using the platform's
+/// scatter/gather send call (`sendmsg`/`WSASendMsg`) where available, so the
+/// kernel reads the pieces straight out of the caller's buffers instead of
+/// an intermediate copy. IPv6 targets and platforms without scatter/gather
+/// support fall back to one `send_to` per buffer.
+fn send_vectored(socket: &UdpSocket, target: SocketAddr, iovecs: &[IoVec]) -> std::io::Result<usize> {
+    match target {
+        // Only the targets `send_vectored_v4` has a native `struct msghdr`/
+        // `WSABUF` layout for; anything else (other unix flavors, IPv6) uses
+        // the `send_to`-per-buffer fallback below.
+        #[cfg(any(
+            target_os = "linux",
+            target_os = "android",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "freebsd",
+            target_os = "openbsd",
+            target_os = "netbsd",
+            target_os = "dragonfly",
+            windows,
+        ))]
+        SocketAddr::V4(v4) => send_vectored_v4(socket, v4, iovecs),
+        _ => send_vectored_fallback(socket, target, iovecs),
+    }
+}
+
+// Scratch arena slot size: a 4-byte length prefix (as bundle elements
+// require) followed by 4092 bytes for one serialized message, so the whole
+// slot matches the 4096-byte buffers `create_osc_message`/`create_osc_bundle`
+// use. A message longer than 4092 bytes does not fit a slot.
+const SEND_BATCH_SLOT_SIZE: usize = 4096;
+
+/// Serializes a batch of messages as an OSC bundle and flushes it in one
+/// syscall via vectored I/O: each message is serialized directly into its
+/// own slot of a scratch arena (length-prefixed, as bundle elements require),
+/// then every slot is gathered onto the wire alongside the bundle header
+/// without first copying them into one contiguous buffer. IPv6 targets, and
+/// platforms without a native scatter/gather path, go through
+/// `send_vectored_fallback` instead, which sends the header and each slot as
+/// separate datagrams.
+///
+/// Returns the number of bytes sent, or a negative error code: `-1` for an
+/// invalid IP/port, `-2` if the socket couldn't be bound, `-3` if the send
+/// itself failed, or `-4` if a message's encoded size (most plausibly a
+/// large blob) wouldn't fit in a single slot.
+#[no_mangle]
+pub extern "C" fn send_osc_batch(ip: *const c_char, port: u16, messages: *const OscMessage, len: usize) -> i32 {
+    let ip_address = match unsafe { CStr::from_ptr(ip) }.to_str() {
+        Ok(ip) => ip,
+        Err(_) => return -1, // Return error code -1 for invalid IP address
+    };
+    let target: SocketAddr = match format!("{}:{}", ip_address, port).parse() {
+        Ok(addr) => addr,
+        Err(_) => return -1,
+    };
+    let socket = match UdpSocket::bind("0.0.0.0:0") {
+        Ok(socket) => socket,
+        Err(_) => return -2, // Return error code -2 for socket binding error
+    };
+
+    let messages = unsafe { slice::from_raw_parts(messages, len) };
+    let mut arena = vec![0u8; len * SEND_BATCH_SLOT_SIZE];
+    let mut slot_lens = vec![0usize; len];
+
+    for (i, msg) in messages.iter().enumerate() {
+        let slot = &mut arena[i * SEND_BATCH_SLOT_SIZE..(i + 1) * SEND_BATCH_SLOT_SIZE];
+        let (prefix, payload) = slot.split_at_mut(4);
+        // write_osc_message is handed exactly the real, remaining backing
+        // bytes of this slot (not a false 4096-byte claim), and bounds-checks
+        // against that length itself, so an oversized message can't run past
+        // this slot into the next one (or past the arena for the last slot).
+        let msg_len = match write_osc_message(payload, msg) {
+            Ok(msg_len) => msg_len,
+            Err(()) => return -4, // Return error code -4 for a message too large to fit a batch slot
+        };
+        prefix.copy_from_slice(&(msg_len as u32).to_be_bytes());
+        slot_lens[i] = msg_len + 4;
+    }
+
+    let header = current_ntp_timetag();
+    let mut header_bytes = [0u8; 16];
+    header_bytes[0..8].copy_from_slice(b"#bundle\0");
+    header_bytes[8..16].copy_from_slice(&header.to_be_bytes());
+
+    let mut iovecs = Vec::with_capacity(len + 1);
+    iovecs.push(IoVec { base: header_bytes.as_ptr(), len: header_bytes.len() });
+    for (i, slot_len) in slot_lens.iter().enumerate() {
+        iovecs.push(IoVec { base: unsafe { arena.as_ptr().add(i * SEND_BATCH_SLOT_SIZE) }, len: *slot_len });
+    }
+
+    match send_vectored(&socket, target, &iovecs) {
+        Ok(sent) => sent as i32,
+        Err(_) => -3, // Return error code -3 for a failed send
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -309,7 +1126,9 @@ mod tests {
         let osc_message = OscMessage {
             address: CString::new("/test_message/meme").unwrap().into_raw(),
             osc_type: OscType::Int,
-            value: OscValue { int: 42, float: 0.0, bool: false, string: std::ptr::null_mut() },
+            value: OscValue { int: 42, float: 0.0, bool: false, string: std::ptr::null_mut(), ..Default::default() },
+            args: std::ptr::null_mut(),
+            arg_count: 0,
         };
 
         create_osc_message(buf.as_mut_ptr(), &osc_message);
@@ -330,17 +1149,23 @@ mod tests {
         let osc_message1 = OscMessage {
             address: CString::new("/test_message/meme").unwrap().into_raw(),
             osc_type: OscType::Int,
-            value: OscValue { int: 42, float: 0.0, bool: false, string: std::ptr::null_mut() },
+            value: OscValue { int: 42, float: 0.0, bool: false, string: std::ptr::null_mut(), ..Default::default() },
+            args: std::ptr::null_mut(),
+            arg_count: 0,
         };
         let osc_message2 = OscMessage {
             address: CString::new("/test_message/meme2").unwrap().into_raw(),
             osc_type: OscType::Float,
-            value: OscValue { int: 0, float: 3.14, bool: false, string: std::ptr::null_mut() },
+            value: OscValue { int: 0, float: 3.14, bool: false, string: std::ptr::null_mut(), ..Default::default() },
+            args: std::ptr::null_mut(),
+            arg_count: 0,
         };
         let osc_message3 = OscMessage {
             address: CString::new("/test_message/meme3").unwrap().into_raw(),
             osc_type: OscType::Bool,
-            value: OscValue { int: 0, float: 0.0, bool: true, string: std::ptr::null_mut() },
+            value: OscValue { int: 0, float: 0.0, bool: true, string: std::ptr::null_mut(), ..Default::default() },
+            args: std::ptr::null_mut(),
+            arg_count: 0,
         };
         let messages = [osc_message1, osc_message2, osc_message3];
 
@@ -427,4 +1252,467 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn parse_truncated_packet_does_not_panic() {
+        let buf = [47, 116, 101, 115, 116, 0, 0, 0, 44, 105, 0, 0, 0, 0];
+        match parse(&buf) {
+            Err(ParserError::NotEnoughBytes) => {}
+            Ok(_) => panic!("Expected NotEnoughBytes, got Ok"),
+            Err(e) => panic!("Expected NotEnoughBytes, got {:?}", e),
+        }
+    }
+
+    #[test]
+    fn parse_empty_packet_does_not_panic() {
+        let buf: [u8; 0] = [];
+        match parse(&buf) {
+            Err(ParserError::InvalidAddress) => {}
+            Ok(_) => panic!("Expected InvalidAddress, got Ok"),
+            Err(e) => panic!("Expected InvalidAddress, got {:?}", e),
+        }
+    }
+
+    #[test]
+    fn parse_multiple_arguments() {
+        // Address "/test", type tags ",iif", then int 1, int 2, float 3.0
+        let mut buf = [
+            47, 116, 101, 115, 116, 0, 0, 0, // "/test\0\0\0"
+            44, 105, 105, 102, 0, 0, 0, 0, // ",iif\0\0\0"
+            0, 0, 0, 0, // 1
+            0, 0, 0, 0, // 2
+            0, 0, 0, 0, // 3.0
+        ];
+        buf[19] = 1;
+        buf[23] = 2;
+        buf[24..28].copy_from_slice(&3.0_f32.to_be_bytes());
+
+        match parse(&buf) {
+            Ok(message) => {
+                assert_eq!(message.arg_count, 3, "Expected three decoded arguments.");
+                assert_eq!(message.value.int, 1, "First argument should mirror the single-value fields.");
+                let args = unsafe { slice::from_raw_parts(message.args, message.arg_count) };
+                assert_eq!(args[0].value.int, 1);
+                assert_eq!(args[1].value.int, 2);
+                assert_eq!(args[2].value.float, 3.0);
+            }
+            Err(e) => panic!("Error: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn serialize_and_parse_multiple_arguments() {
+        let args = [
+            OscArg { osc_type: OscType::Int, value: OscValue { int: 7, float: 0.0, bool: false, string: std::ptr::null(), ..Default::default() } },
+            OscArg { osc_type: OscType::Float, value: OscValue { int: 0, float: 1.5, bool: false, string: std::ptr::null(), ..Default::default() } },
+            OscArg { osc_type: OscType::Bool, value: OscValue { int: 0, float: 0.0, bool: true, string: std::ptr::null(), ..Default::default() } },
+        ];
+        let mut args = args;
+        let osc_message = OscMessage {
+            address: CString::new("/test_message/multi").unwrap().into_raw(),
+            osc_type: args[0].osc_type,
+            value: args[0].value,
+            args: args.as_mut_ptr(),
+            arg_count: args.len(),
+        };
+
+        let mut buf: [u8; 4096] = [0; 4096];
+        create_osc_message(buf.as_mut_ptr(), &osc_message);
+
+        match parse(&buf) {
+            Ok(message) => {
+                assert_eq!(message.arg_count, 3, "Expected three decoded arguments.");
+                let decoded = unsafe { slice::from_raw_parts(message.args, message.arg_count) };
+                assert_eq!(decoded[0].value.int, 7);
+                assert_eq!(decoded[1].value.float, 1.5);
+                assert!(decoded[2].value.bool);
+            }
+            Err(e) => panic!("Error: {:?}", e),
+        }
+    }
+
+    fn round_trip(osc_type: OscType, value: OscValue) -> OscMessage {
+        let osc_message = OscMessage {
+            address: CString::new("/test_message/round_trip").unwrap().into_raw(),
+            osc_type,
+            value,
+            args: std::ptr::null_mut(),
+            arg_count: 0,
+        };
+        let mut buf: [u8; 4096] = [0; 4096];
+        create_osc_message(buf.as_mut_ptr(), &osc_message);
+        parse(&buf).expect("Failed to parse round-tripped message.")
+    }
+
+    #[test]
+    fn round_trip_int64() {
+        let message = round_trip(OscType::Int64, OscValue { int64: -123456789012345, ..Default::default() });
+        assert_eq!(message.value.int64, -123456789012345);
+    }
+
+    #[test]
+    fn round_trip_double() {
+        let message = round_trip(OscType::Double, OscValue { double: 6.9420, ..Default::default() });
+        assert_eq!(message.value.double, 6.9420);
+    }
+
+    #[test]
+    fn round_trip_timetag() {
+        let message = round_trip(OscType::Timetag, OscValue { int64: 0x0011_2233_4455_6677, ..Default::default() });
+        assert_eq!(message.value.int64, 0x0011_2233_4455_6677);
+    }
+
+    #[test]
+    fn round_trip_char() {
+        let message = round_trip(OscType::Char, OscValue { int: 'x' as i32, ..Default::default() });
+        assert_eq!(message.value.int, 'x' as i32);
+    }
+
+    #[test]
+    fn round_trip_rgba() {
+        let message = round_trip(OscType::Rgba, OscValue { int: 0x11223344u32 as i32, ..Default::default() });
+        assert_eq!(message.value.int, 0x11223344u32 as i32);
+    }
+
+    #[test]
+    fn round_trip_midi() {
+        let message = round_trip(OscType::Midi, OscValue { int: 0x01902040, ..Default::default() });
+        assert_eq!(message.value.int, 0x01902040);
+    }
+
+    #[test]
+    fn round_trip_nil_and_impulse() {
+        let message = round_trip(OscType::Nil, OscValue::default());
+        assert!(matches!(message.osc_type, OscType::Nil));
+
+        let message = round_trip(OscType::Impulse, OscValue::default());
+        assert!(matches!(message.osc_type, OscType::Impulse));
+    }
+
+    #[test]
+    fn round_trip_blob() {
+        let data = [1u8, 2, 3, 4, 5];
+        let value = OscValue { blob: data.as_ptr(), blob_len: data.len(), ..Default::default() };
+        let message = round_trip(OscType::Blob, value);
+        assert_eq!(message.value.blob_len, 5);
+        let decoded = unsafe { slice::from_raw_parts(message.value.blob, message.value.blob_len) };
+        assert_eq!(decoded, &[1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn create_osc_message_rejects_blob_too_large_for_buffer() {
+        let data = vec![0u8; 4090];
+        let osc_message = OscMessage {
+            address: CString::new("/test").unwrap().into_raw(),
+            osc_type: OscType::Blob,
+            value: OscValue { blob: data.as_ptr(), blob_len: data.len(), ..Default::default() },
+            args: std::ptr::null_mut(),
+            arg_count: 0,
+        };
+
+        let mut buf: [u8; 4096] = [0; 4096];
+        let written = create_osc_message(buf.as_mut_ptr(), &osc_message);
+        assert_eq!(written, 0, "Oversized blob should be rejected instead of overrunning the buffer.");
+    }
+
+    #[test]
+    fn create_osc_bundle_stops_before_message_too_large_to_fit() {
+        let small_message = OscMessage {
+            address: CString::new("/small").unwrap().into_raw(),
+            osc_type: OscType::Int,
+            value: OscValue { int: 1, ..Default::default() },
+            args: std::ptr::null_mut(),
+            arg_count: 0,
+        };
+        let big_blob = vec![0u8; 4090];
+        let big_message = OscMessage {
+            address: CString::new("/b").unwrap().into_raw(),
+            osc_type: OscType::Blob,
+            value: OscValue { blob: big_blob.as_ptr(), blob_len: big_blob.len(), ..Default::default() },
+            args: std::ptr::null_mut(),
+            arg_count: 0,
+        };
+        let messages = [small_message, big_message];
+
+        let mut buf: [u8; 4096] = [0; 4096];
+        let mut index: usize = 0;
+        let len = create_osc_bundle(buf.as_mut_ptr(), messages.as_ptr(), messages.len(), &mut index);
+
+        // Only the small message should have made it into the bundle; the
+        // oversized one stops the loop rather than writing past the buffer.
+        let expected_len = 16 + 4 + 16; // bundle header+timetag, length prefix, then the small message
+        assert_eq!(len, expected_len);
+        match parse_packet(&buf[..len]) {
+            Ok(OscPacket::Bundle(_, decoded)) => assert_eq!(decoded.len(), 1),
+            other => panic!("Expected a one-message bundle, got {:?}", other.is_ok()),
+        }
+    }
+
+    #[test]
+    fn send_osc_batch_delivers_one_bundle() {
+        let receiver = UdpSocket::bind("127.0.0.1:0").unwrap();
+        receiver.set_read_timeout(Some(std::time::Duration::from_secs(1))).unwrap();
+        let port = receiver.local_addr().unwrap().port();
+
+        let osc_message1 = OscMessage {
+            address: CString::new("/batch/one").unwrap().into_raw(),
+            osc_type: OscType::Int,
+            value: OscValue { int: 1, ..Default::default() },
+            args: std::ptr::null_mut(),
+            arg_count: 0,
+        };
+        let osc_message2 = OscMessage {
+            address: CString::new("/batch/two").unwrap().into_raw(),
+            osc_type: OscType::Float,
+            value: OscValue { float: 2.0, ..Default::default() },
+            args: std::ptr::null_mut(),
+            arg_count: 0,
+        };
+        let messages = [osc_message1, osc_message2];
+
+        let ip = CString::new("127.0.0.1").unwrap();
+        let sent = send_osc_batch(ip.as_ptr(), port, messages.as_ptr(), messages.len());
+        assert!(sent > 0, "send_osc_batch should report bytes sent, got {}", sent);
+
+        let mut buf = [0u8; 4096];
+        let (amt, _) = receiver.recv_from(&mut buf).unwrap();
+        let packet = &buf[..amt];
+
+        assert_eq!(&packet[0..8], b"#bundle\0", "Batch should arrive as a single OSC bundle.");
+
+        let mut ix = 16; // past the header and timetag
+        let mut decoded = Vec::new();
+        while ix < packet.len() {
+            let mut len_bytes = [0u8; 4];
+            len_bytes.copy_from_slice(&packet[ix..ix + 4]);
+            let element_len = u32::from_be_bytes(len_bytes) as usize;
+            ix += 4;
+            let message = parse(&packet[ix..ix + element_len]).expect("Failed to parse bundled element.");
+            let address = unsafe { CStr::from_ptr(message.address) }.to_str().unwrap().to_owned();
+            decoded.push(address);
+            ix += element_len;
+        }
+
+        assert_eq!(decoded, vec!["/batch/one", "/batch/two"]);
+    }
+
+    #[test]
+    fn send_osc_batch_rejects_message_too_large_for_a_slot() {
+        let big_blob = vec![0u8; 4090];
+        let osc_message = OscMessage {
+            address: CString::new("/batch/big").unwrap().into_raw(),
+            osc_type: OscType::Blob,
+            value: OscValue { blob: big_blob.as_ptr(), blob_len: big_blob.len(), ..Default::default() },
+            args: std::ptr::null_mut(),
+            arg_count: 0,
+        };
+        let messages = [osc_message];
+
+        let ip = CString::new("127.0.0.1").unwrap();
+        let result = send_osc_batch(ip.as_ptr(), 9, messages.as_ptr(), messages.len());
+        assert_eq!(result, -4, "Oversized message should be rejected instead of overrunning its arena slot.");
+    }
+
+    #[test]
+    fn parse_packet_decodes_a_bundle() {
+        let osc_message1 = OscMessage {
+            address: CString::new("/bundle/one").unwrap().into_raw(),
+            osc_type: OscType::Int,
+            value: OscValue { int: 1, ..Default::default() },
+            args: std::ptr::null_mut(),
+            arg_count: 0,
+        };
+        let osc_message2 = OscMessage {
+            address: CString::new("/bundle/two").unwrap().into_raw(),
+            osc_type: OscType::Float,
+            value: OscValue { float: 2.0, ..Default::default() },
+            args: std::ptr::null_mut(),
+            arg_count: 0,
+        };
+        let messages = [osc_message1, osc_message2];
+
+        let mut buf: [u8; 4096] = [0; 4096];
+        let mut index: usize = 0;
+        let len = create_osc_bundle(buf.as_mut_ptr(), messages.as_ptr(), messages.len(), &mut index);
+
+        match parse_packet(&buf[..len]) {
+            Ok(OscPacket::Bundle(timetag, decoded)) => {
+                assert!(timetag > 0, "Expected a non-zero NTP timetag.");
+                assert_eq!(decoded.len(), 2);
+                let address1 = unsafe { CStr::from_ptr(decoded[0].address) }.to_str().unwrap();
+                let address2 = unsafe { CStr::from_ptr(decoded[1].address) }.to_str().unwrap();
+                assert_eq!(address1, "/bundle/one");
+                assert_eq!(address2, "/bundle/two");
+                assert_eq!(decoded[0].value.int, 1);
+                assert_eq!(decoded[1].value.float, 2.0);
+            }
+            Ok(OscPacket::Message(_)) => panic!("Expected a bundle, got a single message."),
+            Err(e) => panic!("Error: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn parse_packet_flattens_nested_bundles() {
+        // Build an inner bundle containing one message...
+        let inner_message = OscMessage {
+            address: CString::new("/bundle/nested").unwrap().into_raw(),
+            osc_type: OscType::Int,
+            value: OscValue { int: 42, ..Default::default() },
+            args: std::ptr::null_mut(),
+            arg_count: 0,
+        };
+        let inner_messages = [inner_message];
+        let mut inner_buf: [u8; 4096] = [0; 4096];
+        let mut inner_index: usize = 0;
+        let inner_len = create_osc_bundle(inner_buf.as_mut_ptr(), inner_messages.as_ptr(), inner_messages.len(), &mut inner_index);
+
+        // ...then hand-assemble an outer bundle whose single element is that inner bundle.
+        let mut outer_buf: [u8; 4096] = [0; 4096];
+        outer_buf[0..8].copy_from_slice(b"#bundle\0");
+        outer_buf[8..16].copy_from_slice(&current_ntp_timetag().to_be_bytes());
+        outer_buf[16..20].copy_from_slice(&(inner_len as u32).to_be_bytes());
+        outer_buf[20..20 + inner_len].copy_from_slice(&inner_buf[..inner_len]);
+        let outer_len = 20 + inner_len;
+
+        match parse_packet(&outer_buf[..outer_len]) {
+            Ok(OscPacket::Bundle(_, decoded)) => {
+                assert_eq!(decoded.len(), 1);
+                let address = unsafe { CStr::from_ptr(decoded[0].address) }.to_str().unwrap();
+                assert_eq!(address, "/bundle/nested");
+                assert_eq!(decoded[0].value.int, 42);
+            }
+            Ok(OscPacket::Message(_)) => panic!("Expected a bundle, got a single message."),
+            Err(e) => panic!("Error: {:?}", e),
+        }
+    }
+
+    static RECEIVED_COUNT: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+    extern "C" fn count_message_callback(msg: *mut OscMessage) {
+        free_osc_message(msg);
+        RECEIVED_COUNT.fetch_add(1, Ordering::SeqCst);
+    }
+
+    extern "C" fn count_bundle_callback(bundle: *mut OscBundle) {
+        free_osc_bundle(bundle);
+    }
+
+    #[test]
+    fn start_socket_receives_continuously_and_stops_cleanly() {
+        RECEIVED_COUNT.store(0, Ordering::SeqCst);
+
+        let port = {
+            let probe = UdpSocket::bind("127.0.0.1:0").unwrap();
+            probe.local_addr().unwrap().port()
+        };
+
+        let ip = CString::new("127.0.0.1").unwrap();
+        let mut thread_ptr: *mut c_void = std::ptr::null_mut();
+        let result = start_socket(
+            ip.as_ptr(),
+            port,
+            &mut thread_ptr as *mut *mut c_void as *mut c_void,
+            count_message_callback,
+            count_bundle_callback,
+        );
+        assert_eq!(result, 0);
+
+        let sender = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let osc_message = OscMessage {
+            address: CString::new("/continuous/one").unwrap().into_raw(),
+            osc_type: OscType::Int,
+            value: OscValue { int: 1, ..Default::default() },
+            args: std::ptr::null_mut(),
+            arg_count: 0,
+        };
+        let mut buf: [u8; 4096] = [0; 4096];
+        let len = create_osc_message(buf.as_mut_ptr(), &osc_message);
+
+        // Send several packets back to back; the receive loop keeps listening
+        // after the first, so it should pick up all of them.
+        for _ in 0..3 {
+            sender.send_to(&buf[..len], format!("127.0.0.1:{}", port)).unwrap();
+        }
+        std::thread::sleep(Duration::from_millis(300));
+        assert_eq!(RECEIVED_COUNT.load(Ordering::SeqCst), 3);
+
+        // `stop_socket` must signal the loop and then actually join it; the
+        // loop sees the flag within one read-timeout tick, so this returns.
+        stop_socket(thread_ptr);
+    }
+
+    #[test]
+    fn free_osc_message_frees_args_and_blob_without_crashing() {
+        // Build a multi-argument message with a string and a blob argument,
+        // the same way the recv path would hand one to the message callback.
+        let osc_message = OscMessage {
+            address: CString::new("/test_message/multi").unwrap().into_raw(),
+            osc_type: OscType::Int,
+            value: OscValue { int: 7, ..Default::default() },
+            args: std::ptr::null_mut(),
+            arg_count: 0,
+        };
+        let args: Box<[OscArg]> = vec![
+            OscArg { osc_type: OscType::Int, value: OscValue { int: 7, ..Default::default() } },
+            OscArg { osc_type: OscType::String, value: OscValue { string: CString::new("hi").unwrap().into_raw(), ..Default::default() } },
+            OscArg { osc_type: OscType::Blob, value: OscValue { blob: Box::into_raw(vec![1u8, 2, 3].into_boxed_slice()) as *const c_uchar, blob_len: 3, ..Default::default() } },
+        ].into_boxed_slice();
+        let arg_count = args.len();
+        let msg = OscMessage { args: Box::into_raw(args) as *mut OscArg, arg_count, ..osc_message };
+        let msg = Box::into_raw(Box::new(msg));
+
+        free_osc_message(msg);
+        free_osc_message(std::ptr::null_mut());
+    }
+
+    #[test]
+    fn free_osc_message_contents_frees_a_parse_osc_message_without_crashing() {
+        // "/test", type tags ",si", then string "hi\0\0" and int 9 -- parse_osc
+        // hands back a caller-owned OscMessage whose args/string are still
+        // heap-allocated by this crate, so they need freeing too.
+        let mut buf = [
+            47, 116, 101, 115, 116, 0, 0, 0, // "/test\0\0\0"
+            44, 115, 105, 0, // ",si\0"
+            104, 105, 0, 0, // "hi\0\0"
+            0, 0, 0, 9, // 9
+        ];
+        let len = buf.len();
+
+        // A stack-allocated OscMessage, as a C# caller would pass by ref.
+        let mut msg = OscMessage {
+            address: std::ptr::null(),
+            osc_type: OscType::Int,
+            value: OscValue::default(),
+            args: std::ptr::null_mut(),
+            arg_count: 0,
+        };
+        assert!(parse_osc(buf.as_mut_ptr(), len, &mut msg));
+        assert_eq!(msg.arg_count, 2);
+
+        free_osc_message_contents(&mut msg);
+        free_osc_message_contents(std::ptr::null_mut());
+    }
+
+    #[test]
+    fn free_osc_bundle_frees_every_contained_message() {
+        let message1 = OscMessage {
+            address: CString::new("/bundle/one").unwrap().into_raw(),
+            osc_type: OscType::Int,
+            value: OscValue { int: 1, ..Default::default() },
+            args: std::ptr::null_mut(),
+            arg_count: 0,
+        };
+        let message2 = OscMessage {
+            address: CString::new("/bundle/two").unwrap().into_raw(),
+            osc_type: OscType::Float,
+            value: OscValue { float: 2.0, ..Default::default() },
+            args: std::ptr::null_mut(),
+            arg_count: 0,
+        };
+        let messages = Box::into_raw(vec![message1, message2].into_boxed_slice()) as *mut OscMessage;
+        let bundle = Box::into_raw(Box::new(OscBundle { timetag: 0, messages, message_count: 2 }));
+
+        free_osc_bundle(bundle);
+        free_osc_bundle(std::ptr::null_mut());
+    }
 }